@@ -0,0 +1,7 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Build hosts don't reliably have a system `protoc`; point at the
+    // vendored binary so `cargo build` works without extra setup.
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::compile_protos("proto/events.proto")?;
+    Ok(())
+}
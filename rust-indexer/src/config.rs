@@ -0,0 +1,303 @@
+use std::env;
+use std::fmt;
+
+use alloy::primitives::U256;
+use alloy::providers::{DynProvider, Provider, ProviderBuilder, WsConnect};
+use clap::Parser;
+use url::Url;
+
+use crate::contracts::EventSubscription;
+use crate::provider_pool::ProviderPool;
+use crate::trader::ContractCall;
+use crate::webhook::WebhookTarget;
+
+/// Command-line flags that override the corresponding environment variables.
+#[derive(Parser, Debug)]
+#[command(name = "kakuzu-observer", about = "ClawTrading chain observer")]
+pub struct Cli {
+    /// Perform execution-path calls (`eth_call`/`estimate_gas`) without ever
+    /// broadcasting a signed transaction.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Overrides the chain id the bot expects to be connected to.
+    #[arg(long)]
+    pub chain_id: Option<u64>,
+
+    /// Overrides `ETH_RPC_URL`. Accepts a comma-separated list, the same as
+    /// the environment variable.
+    #[arg(long)]
+    pub rpc_url: Option<String>,
+}
+
+/// Error produced by [`Config::from_env`] when the environment (as overridden
+/// by CLI flags) doesn't describe a valid, runnable configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    MissingEnv(&'static str),
+    InvalidValue { field: &'static str, reason: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingEnv(name) => write!(f, "missing required environment variable: {name}"),
+            ConfigError::InvalidValue { field, reason } => {
+                write!(f, "invalid value for {field}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Runtime settings for the observer, resolved once at startup from
+/// environment variables and CLI overrides. The gRPC listen address
+/// (`GRPC_LISTEN_ADDR`) is not part of it; `main` reads that variable
+/// directly. Replaces the ad-hoc
+/// `env::var(...).expect(...)` calls that used to be scattered through
+/// `main`.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub rpc_urls: Vec<Url>,
+    pub chain_id: Option<u64>,
+    pub webhook_urls: Vec<String>,
+    pub webhook_secret: Option<String>,
+    pub signer_key: Option<String>,
+    pub filters: Vec<String>,
+    pub execute_calls: Vec<String>,
+    pub dry_run: bool,
+    pub priority_fee_wei: Option<u128>,
+}
+
+impl Config {
+    /// Builds a [`Config`] from the environment, applying `cli` as overrides.
+    /// Validates everything up front so startup fails with a clear
+    /// [`ConfigError`] instead of panicking deep in `main`.
+    pub fn from_env(cli: &Cli) -> Result<Self, ConfigError> {
+        let raw_rpc_urls = cli
+            .rpc_url
+            .clone()
+            .or_else(|| env::var("ETH_RPC_URL").ok())
+            .ok_or(ConfigError::MissingEnv("ETH_RPC_URL"))?;
+
+        let rpc_urls = raw_rpc_urls
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse::<Url>().map_err(|err| ConfigError::InvalidValue {
+                    field: "ETH_RPC_URL",
+                    reason: err.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if rpc_urls.is_empty() {
+            return Err(ConfigError::MissingEnv("ETH_RPC_URL"));
+        }
+
+        let chain_id = match cli.chain_id {
+            Some(id) => Some(id),
+            None => env::var("CHAIN_ID")
+                .ok()
+                .map(|v| {
+                    v.parse::<u64>().map_err(|err| ConfigError::InvalidValue {
+                        field: "CHAIN_ID",
+                        reason: err.to_string(),
+                    })
+                })
+                .transpose()?,
+        };
+
+        let webhook_urls = env::var("WEBHOOK_URLS")
+            .ok()
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let webhook_secret = env::var("WEBHOOK_SECRET").ok();
+
+        // Never logged: the signer key is only ever handed to `Trader`.
+        let signer_key = env::var("SIGNER_PRIVATE_KEY").ok();
+
+        let filters = env::var("CONTRACT_FILTERS")
+            .ok()
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let execute_calls = env::var("EXECUTE_CALLS")
+            .ok()
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let dry_run = cli.dry_run || env::var("DRY_RUN").is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+
+        let priority_fee_wei = env::var("PRIORITY_FEE_WEI")
+            .ok()
+            .map(|v| {
+                v.parse::<u128>().map_err(|err| ConfigError::InvalidValue {
+                    field: "PRIORITY_FEE_WEI",
+                    reason: err.to_string(),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            rpc_urls,
+            chain_id,
+            webhook_urls,
+            webhook_secret,
+            signer_key,
+            filters,
+            execute_calls,
+            dry_run,
+            priority_fee_wei,
+        })
+    }
+
+    /// Constructs the configured provider, connecting over WS or HTTP
+    /// depending on the scheme of the primary (first) RPC URL.
+    pub async fn build_provider(&self) -> Result<DynProvider, ConfigError> {
+        let primary = &self.rpc_urls[0];
+
+        match primary.scheme() {
+            "ws" | "wss" => {
+                let provider = ProviderBuilder::new()
+                    .on_ws(WsConnect::new(primary.clone()))
+                    .await
+                    .map_err(|err| ConfigError::InvalidValue {
+                        field: "ETH_RPC_URL",
+                        reason: err.to_string(),
+                    })?;
+                Ok(provider.erased())
+            }
+            "http" | "https" => {
+                let provider = ProviderBuilder::new().on_http(primary.clone());
+                Ok(provider.erased())
+            }
+            other => Err(ConfigError::InvalidValue {
+                field: "ETH_RPC_URL",
+                reason: format!("unsupported scheme `{other}`, expected http(s) or ws(s)"),
+            }),
+        }
+    }
+
+    /// Builds a [`ProviderPool`] over every configured RPC URL, for the
+    /// read-path call sites that want retry and failover rather than a
+    /// single live connection.
+    pub fn build_provider_pool(&self) -> Result<ProviderPool, ConfigError> {
+        let urls = self
+            .rpc_urls
+            .iter()
+            .map(Url::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        ProviderPool::new(&urls).map_err(|err| ConfigError::InvalidValue {
+            field: "ETH_RPC_URL",
+            reason: err.to_string(),
+        })
+    }
+
+    /// The webhook targets described by `WEBHOOK_URLS`/`WEBHOOK_SECRET`.
+    pub fn webhook_targets(&self) -> Vec<WebhookTarget> {
+        self.webhook_urls
+            .iter()
+            .map(|url| WebhookTarget {
+                url: url.clone(),
+                secret: self.webhook_secret.clone(),
+            })
+            .collect()
+    }
+
+    /// Parses `CONTRACT_FILTERS` entries of the form `address:kind` (kind is
+    /// one of `transfer`, `swap`, `sync`) into [`EventSubscription`]s.
+    pub fn event_subscriptions(&self) -> Result<Vec<EventSubscription>, ConfigError> {
+        self.filters
+            .iter()
+            .map(|spec| {
+                let (address, kind) = spec.split_once(':').ok_or_else(|| ConfigError::InvalidValue {
+                    field: "CONTRACT_FILTERS",
+                    reason: format!("expected `address:kind`, got `{spec}`"),
+                })?;
+
+                let address: alloy::primitives::Address =
+                    address.parse().map_err(|err: alloy::primitives::hex::FromHexError| {
+                        ConfigError::InvalidValue {
+                            field: "CONTRACT_FILTERS",
+                            reason: err.to_string(),
+                        }
+                    })?;
+
+                match kind {
+                    "transfer" => Ok(EventSubscription::transfer(address)),
+                    "swap" => Ok(EventSubscription::swap(address)),
+                    "sync" => Ok(EventSubscription::sync(address)),
+                    other => Err(ConfigError::InvalidValue {
+                        field: "CONTRACT_FILTERS",
+                        reason: format!("unknown event kind `{other}`"),
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Parses `EXECUTE_CALLS` entries of the form `to:data:value` (`data` and
+    /// `value` are hex-encoded, `value` defaulting to `0` if empty) into
+    /// [`ContractCall`]s for [`crate::trader::Trader`] to execute at startup.
+    pub fn execute_calls(&self) -> Result<Vec<ContractCall>, ConfigError> {
+        self.execute_calls
+            .iter()
+            .map(|spec| {
+                let mut parts = spec.splitn(3, ':');
+                let to = parts.next().ok_or_else(|| ConfigError::InvalidValue {
+                    field: "EXECUTE_CALLS",
+                    reason: format!("expected `to:data[:value]`, got `{spec}`"),
+                })?;
+                let data = parts.next().ok_or_else(|| ConfigError::InvalidValue {
+                    field: "EXECUTE_CALLS",
+                    reason: format!("expected `to:data[:value]`, got `{spec}`"),
+                })?;
+                let value = parts.next().unwrap_or("0");
+
+                let to: alloy::primitives::Address =
+                    to.parse().map_err(|err: alloy::primitives::hex::FromHexError| ConfigError::InvalidValue {
+                        field: "EXECUTE_CALLS",
+                        reason: err.to_string(),
+                    })?;
+                let data: alloy::primitives::Bytes =
+                    data.parse().map_err(|err: alloy::primitives::hex::FromHexError| ConfigError::InvalidValue {
+                        field: "EXECUTE_CALLS",
+                        reason: err.to_string(),
+                    })?;
+                let value = if value.is_empty() {
+                    U256::ZERO
+                } else {
+                    U256::from_str_radix(value.trim_start_matches("0x"), 16).map_err(|err| ConfigError::InvalidValue {
+                        field: "EXECUTE_CALLS",
+                        reason: err.to_string(),
+                    })?
+                };
+
+                Ok(ContractCall { to, data, value })
+            })
+            .collect()
+    }
+}
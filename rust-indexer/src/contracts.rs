@@ -0,0 +1,248 @@
+use std::collections::HashSet;
+
+use alloy::primitives::{Address, B256};
+use alloy::providers::Provider;
+use alloy::rpc::types::{Filter, Log};
+use alloy::sol;
+use alloy::sol_types::SolEvent;
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+
+use crate::events::ObserverEvent;
+
+sol! {
+    #[derive(Debug)]
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    #[derive(Debug)]
+    event Swap(
+        address indexed sender,
+        uint256 amount0In,
+        uint256 amount1In,
+        uint256 amount0Out,
+        uint256 amount1Out,
+        address indexed to
+    );
+
+    #[derive(Debug)]
+    event Sync(uint112 reserve0, uint112 reserve1);
+}
+
+/// A decoded contract event, tagged with its origin so consumers can dedupe
+/// on reorg and filter by the emitting contract.
+#[derive(Debug, Clone)]
+pub struct ContractEvent {
+    pub address: Address,
+    pub block_number: u64,
+    pub tx_hash: B256,
+    pub log_index: u64,
+    pub kind: ContractEventKind,
+}
+
+// The inner payloads are only ever read through `{:?}` (see
+// `WebhookPayload::from`), which clippy's dead-code analysis doesn't credit
+// as a use.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ContractEventKind {
+    Transfer(Transfer),
+    Swap(Swap),
+    Sync(Sync),
+}
+
+/// A single (contract address, event signature) a caller wants decoded and
+/// delivered over the event channel.
+#[derive(Debug, Clone)]
+pub struct EventSubscription {
+    pub address: Address,
+    pub signature: B256,
+}
+
+impl EventSubscription {
+    pub fn transfer(address: Address) -> Self {
+        Self { address, signature: Transfer::SIGNATURE_HASH }
+    }
+
+    pub fn swap(address: Address) -> Self {
+        Self { address, signature: Swap::SIGNATURE_HASH }
+    }
+
+    pub fn sync(address: Address) -> Self {
+        Self { address, signature: Sync::SIGNATURE_HASH }
+    }
+}
+
+/// Watches a set of registered contract events over a provider subscription
+/// and decodes matching logs into [`ContractEvent`]s delivered on the shared
+/// event channel.
+pub struct EventWatcher<P> {
+    provider: P,
+    subscriptions: Vec<EventSubscription>,
+    tx: mpsc::Sender<ObserverEvent>,
+}
+
+impl<P> EventWatcher<P>
+where
+    P: Provider + Clone + 'static,
+{
+    pub fn new(provider: P, tx: mpsc::Sender<ObserverEvent>) -> Self {
+        Self {
+            provider,
+            subscriptions: Vec::new(),
+            tx,
+        }
+    }
+
+    /// Registers interest in a contract event. Returns `self` so calls can be
+    /// chained.
+    pub fn watch(mut self, subscription: EventSubscription) -> Self {
+        self.subscriptions.push(subscription);
+        self
+    }
+
+    fn filter(&self) -> Filter {
+        let addresses: Vec<Address> = self.subscriptions.iter().map(|s| s.address).collect();
+        let signatures: Vec<B256> = self.subscriptions.iter().map(|s| s.signature).collect();
+        Filter::new().address(addresses).event_signature(signatures)
+    }
+
+    /// Runs the log subscription until the stream ends or the event channel
+    /// receiver is dropped.
+    pub async fn run(self) -> Result<(), Box<dyn std::error::Error + Send + std::marker::Sync>> {
+        let filter = self.filter();
+        let registered = registered_pairs(&self.subscriptions);
+        let sub = self.provider.subscribe_logs(&filter).await?;
+        let mut stream = sub.into_stream();
+
+        while let Some(log) = stream.next().await {
+            let Some(&signature) = log.inner.data.topics().first() else {
+                continue;
+            };
+            if !registered.contains(&(log.inner.address, signature)) {
+                continue;
+            }
+
+            if let Some(event) = decode_log(&log) {
+                if self.tx.send(ObserverEvent::Contract(Box::new(event))).await.is_err() {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The exact (address, signature) pairs a set of subscriptions registered.
+/// `EventWatcher::filter` only ORs addresses and signatures independently,
+/// so a log matching one subscription's address and another's signature
+/// still passes the RPC-level filter; this set is checked against each log
+/// before it's decoded and forwarded so only genuinely-registered pairs get
+/// through.
+fn registered_pairs(subscriptions: &[EventSubscription]) -> HashSet<(Address, B256)> {
+    subscriptions.iter().map(|s| (s.address, s.signature)).collect()
+}
+
+/// Decodes a log against each known event type, returning the first match
+/// tagged with its emitting address, block number, tx hash, and log index.
+fn decode_log(log: &Log) -> Option<ContractEvent> {
+    let address = log.inner.address;
+    let block_number = log.block_number?;
+    let tx_hash = log.transaction_hash?;
+    let log_index = log.log_index?;
+
+    let kind = if let Ok(decoded) = Transfer::decode_log(&log.inner, true) {
+        ContractEventKind::Transfer(decoded.data)
+    } else if let Ok(decoded) = Swap::decode_log(&log.inner, true) {
+        ContractEventKind::Swap(decoded.data)
+    } else if let Ok(decoded) = Sync::decode_log(&log.inner, true) {
+        ContractEventKind::Sync(decoded.data)
+    } else {
+        return None;
+    };
+
+    Some(ContractEvent {
+        address,
+        block_number,
+        tx_hash,
+        log_index,
+        kind,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, U256};
+
+    fn log_with_data(address_emitting: Address, log_data: alloy::primitives::LogData) -> Log {
+        Log {
+            inner: alloy::primitives::Log { address: address_emitting, data: log_data },
+            block_hash: None,
+            block_number: Some(42),
+            block_timestamp: None,
+            transaction_hash: Some(B256::repeat_byte(0xAB)),
+            transaction_index: None,
+            log_index: Some(3),
+            removed: false,
+        }
+    }
+
+    #[test]
+    fn decode_log_dispatches_transfer() {
+        let contract = address!("0000000000000000000000000000000000000001");
+        let transfer = Transfer {
+            from: address!("0000000000000000000000000000000000000002"),
+            to: address!("0000000000000000000000000000000000000003"),
+            value: U256::from(1_000u64),
+        };
+        let log = log_with_data(contract, transfer.encode_log_data());
+
+        let decoded = decode_log(&log).expect("transfer log should decode");
+        assert_eq!(decoded.address, contract);
+        assert_eq!(decoded.block_number, 42);
+        assert_eq!(decoded.log_index, 3);
+        assert!(matches!(decoded.kind, ContractEventKind::Transfer(_)));
+    }
+
+    #[test]
+    fn decode_log_dispatches_sync() {
+        let contract = address!("0000000000000000000000000000000000000004");
+        let sync = Sync { reserve0: 1.try_into().unwrap(), reserve1: 2.try_into().unwrap() };
+        let log = log_with_data(contract, sync.encode_log_data());
+
+        let decoded = decode_log(&log).expect("sync log should decode");
+        assert!(matches!(decoded.kind, ContractEventKind::Sync(_)));
+    }
+
+    #[test]
+    fn registered_pairs_does_not_cross_pair_addresses_and_signatures() {
+        let contract_a = address!("00000000000000000000000000000000000000aa");
+        let contract_b = address!("00000000000000000000000000000000000000bb");
+        let subscriptions = vec![
+            EventSubscription::transfer(contract_a),
+            EventSubscription::swap(contract_b),
+        ];
+
+        let pairs = registered_pairs(&subscriptions);
+
+        assert!(pairs.contains(&(contract_a, Transfer::SIGNATURE_HASH)));
+        assert!(pairs.contains(&(contract_b, Swap::SIGNATURE_HASH)));
+        // A Swap from contract_a was never registered, even though
+        // contract_a is a known address and Swap is a known signature.
+        assert!(!pairs.contains(&(contract_a, Swap::SIGNATURE_HASH)));
+        assert!(!pairs.contains(&(contract_b, Transfer::SIGNATURE_HASH)));
+    }
+
+    #[test]
+    fn decode_log_returns_none_for_unknown_event() {
+        let contract = address!("0000000000000000000000000000000000000005");
+        let unrelated = alloy::primitives::LogData::new_unchecked(
+            vec![B256::repeat_byte(0xFF)],
+            Default::default(),
+        );
+        let log = log_with_data(contract, unrelated);
+
+        assert!(decode_log(&log).is_none());
+    }
+}
@@ -0,0 +1,19 @@
+use alloy::rpc::types::Header;
+use alloy::primitives::TxHash;
+
+use crate::contracts::ContractEvent;
+
+/// An event observed from the chain, handed off to downstream consumers
+/// over the internal event channel.
+#[derive(Debug, Clone)]
+pub enum ObserverEvent {
+    /// A new head was announced via `newHeads`. Boxed since `Header` is much
+    /// larger than the other variants' payloads.
+    NewHead(Box<Header>),
+    /// A transaction hash was announced via `newPendingTransactions`.
+    PendingTransaction(TxHash),
+    /// A registered contract event was matched and decoded. Boxed for the
+    /// same reason as `NewHead`: `ContractEvent` carries a decoded `sol!`
+    /// event and is much larger than the other variants' payloads.
+    Contract(Box<ContractEvent>),
+}
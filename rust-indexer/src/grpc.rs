@@ -0,0 +1,125 @@
+pub mod proto {
+    tonic::include_proto!("clawtrading.events");
+}
+
+use std::collections::HashSet;
+use std::pin::Pin;
+
+use alloy::primitives::Address;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
+use tonic::{Request, Response, Status};
+
+use crate::events::ObserverEvent;
+use crate::webhook::WebhookPayload;
+use proto::watch_events_service_server::WatchEventsService;
+use proto::{Event as ProtoEvent, EventKind, WatchEventsRequest};
+
+/// Bounded channel capacity for a single client's outbound stream. Once it
+/// is full the client's forwarding task waits; if the client then falls
+/// behind the broadcast channel's lag bound it is dropped rather than
+/// allowed to stall the broadcast channel for everyone else.
+const CLIENT_CHANNEL_CAPACITY: usize = 256;
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<ProtoEvent, Status>> + Send + 'static>>;
+
+/// Rebroadcasts the observer's internal event feed to any number of gRPC
+/// clients. Each client gets its own bounded outbound queue and its own
+/// server-side filter; a client that falls behind the shared broadcast
+/// channel's lag bound is dropped instead of slowing down delivery to
+/// everyone else.
+pub struct GrpcEventServer {
+    events: broadcast::Sender<ObserverEvent>,
+}
+
+impl GrpcEventServer {
+    pub fn new(events: broadcast::Sender<ObserverEvent>) -> Self {
+        Self { events }
+    }
+}
+
+#[tonic::async_trait]
+impl WatchEventsService for GrpcEventServer {
+    type WatchEventsStream = EventStream;
+
+    async fn watch_events(
+        &self,
+        request: Request<WatchEventsRequest>,
+    ) -> Result<Response<Self::WatchEventsStream>, Status> {
+        let filter = request.into_inner();
+        let wanted_kinds: HashSet<i32> = filter.kinds.iter().copied().collect();
+
+        let mut wanted_addresses: HashSet<Address> = HashSet::with_capacity(filter.contract_addresses.len());
+        for addr in &filter.contract_addresses {
+            match addr.parse::<Address>() {
+                Ok(parsed) => {
+                    wanted_addresses.insert(parsed);
+                }
+                Err(err) => {
+                    return Err(Status::invalid_argument(format!("invalid contract address `{addr}`: {err}")));
+                }
+            }
+        }
+
+        let mut source = self.events.subscribe();
+        let (tx, client_rx) = mpsc::channel(CLIENT_CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                let event = match source.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        eprintln!("gRPC client lagged by {skipped} events; dropping it");
+                        return;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                };
+
+                if let ObserverEvent::Contract(contract_event) = &event {
+                    if !wanted_addresses.is_empty() && !wanted_addresses.contains(&contract_event.address) {
+                        continue;
+                    }
+                }
+
+                let Some(proto_event) = to_proto(&event) else {
+                    continue;
+                };
+
+                if !wanted_kinds.is_empty() && !wanted_kinds.contains(&proto_event.kind) {
+                    continue;
+                }
+
+                if tx.send(Ok(proto_event)).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(client_rx))))
+    }
+}
+
+fn to_proto(event: &ObserverEvent) -> Option<ProtoEvent> {
+    let payload = WebhookPayload::from(event);
+    let payload_json = serde_json::to_string(&payload).ok()?;
+
+    let (kind, block_number, tx_hash, log_index) = match event {
+        ObserverEvent::NewHead(header) => (EventKind::NewHead, header.number, String::new(), 0),
+        ObserverEvent::PendingTransaction(hash) => (EventKind::PendingTransaction, 0, hash.to_string(), 0),
+        ObserverEvent::Contract(contract_event) => (
+            EventKind::ContractEvent,
+            contract_event.block_number,
+            contract_event.tx_hash.to_string(),
+            contract_event.log_index,
+        ),
+    };
+
+    Some(ProtoEvent {
+        kind: kind as i32,
+        block_number,
+        tx_hash,
+        log_index,
+        payload_json,
+    })
+}
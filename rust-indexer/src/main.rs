@@ -1,33 +1,164 @@
-use alloy::providers::{Provider, ProviderBuilder};
-use dotenv::dotenv;
+mod config;
+mod contracts;
+mod events;
+mod grpc;
+mod provider_pool;
+mod subscription;
+mod trader;
+mod webhook;
+
 use std::env;
-use std::time::Duration;
-use tokio::time::sleep;
-use url::Url;
+
+use clap::Parser;
+use dotenv::dotenv;
+use tokio::sync::{broadcast, mpsc, watch};
+use tonic::transport::Server;
+
+use config::{Cli, Config};
+use contracts::EventWatcher;
+use events::ObserverEvent;
+use grpc::proto::watch_events_service_server::WatchEventsServiceServer;
+use grpc::GrpcEventServer;
+use subscription::SubscriptionEngine;
+use trader::Trader;
+use webhook::WebhookSink;
+
+/// Capacity of the internal broadcast channel gRPC clients are fanned out
+/// from. A client that falls this far behind is dropped.
+const GRPC_BROADCAST_CAPACITY: usize = 1024;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 1. Load .env
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // 1. Load .env, then resolve Config from env + CLI overrides.
     dotenv().ok();
+    let cli = Cli::parse();
+    let config = Config::from_env(&cli)?;
 
-    // 2. Load ETH_RPC_URL or panic
-    let rpc_url_str = env::var("ETH_RPC_URL").expect("Please set ETH_RPC_URL in .env");
-    
     println!("Kakuzu Observer initializing...");
-    println!("Target RPC: {}", rpc_url_str);
+    println!("Target RPC(s): {:?}", config.rpc_urls);
+    if config.dry_run {
+        println!("Running in dry-run mode; no transactions will be broadcast.");
+    }
+
+    // 2. Connect (HTTP or WS, depending on the primary RPC URL's scheme).
+    let provider = config.build_provider().await?;
+
+    // Sanity-check connectivity through the retrying, failover-capable pool
+    // rather than the single live connection above, so a flaky primary
+    // endpoint doesn't take the whole startup down with it.
+    let provider_pool = config.build_provider_pool()?;
+    let observed_chain_id = provider_pool.get_chain_id().await?;
+    println!("Observed chain id (via provider pool): {observed_chain_id}");
+    if let Some(expected) = config.chain_id {
+        if expected != observed_chain_id {
+            return Err(format!(
+                "configured CHAIN_ID={expected} does not match observed chain id {observed_chain_id}"
+            )
+            .into());
+        }
+    }
 
-    // Validate URL parse
-    let rpc_url: Url = rpc_url_str.parse().expect("Invalid RPC URL format");
+    println!("Provider connected. Starting subscription engine...");
 
-    // 3. Setup basic provider (Stub)
-    // In the future, this will be a WsConnect for subscriptions.
-    let _provider = ProviderBuilder::new().on_http(rpc_url);
+    // 3. Wire up the event channel and shutdown signal, then spawn the
+    // subscription engine.
+    let (event_tx, mut event_rx) = mpsc::channel::<ObserverEvent>(1024);
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
-    println!("Provider initialized. Starting subscription loop stub...");
+    let engine = SubscriptionEngine::new(provider.clone(), event_tx.clone());
+    let engine_handle = tokio::spawn(engine.run(shutdown_rx));
 
-    // 4. Subscription Loop Stub
-    loop {
-        println!("Scanning for events... (Stub)");
-        sleep(Duration::from_secs(5)).await;
+    // 4. Optionally watch registered contract events over the same provider.
+    let subscriptions = config.event_subscriptions()?;
+    if !subscriptions.is_empty() {
+        let mut watcher = EventWatcher::new(provider.clone(), event_tx);
+        for subscription in subscriptions {
+            watcher = watcher.watch(subscription);
+        }
+        tokio::spawn(watcher.run());
     }
+
+    // 4a. Optionally sign and submit the configured trades through the
+    // provider pool, so each estimate/call/nonce/broadcast round trip gets
+    // the same retry-and-failover behavior as the startup chain-id check
+    // above, rather than going out over a single unmonitored connection.
+    let execute_calls = config.execute_calls()?;
+    if !execute_calls.is_empty() {
+        let signer_key = config
+            .signer_key
+            .clone()
+            .ok_or("EXECUTE_CALLS is set but SIGNER_PRIVATE_KEY is not")?;
+        let trader = Trader::new(
+            provider_pool,
+            &signer_key,
+            observed_chain_id,
+            config.dry_run,
+            config.priority_fee_wei,
+        )
+        .await?;
+        for call in execute_calls {
+            match trader.execute(call.clone()).await {
+                Ok(Some(tx_hash)) => println!("Trade to {} confirmed submitted: {tx_hash}", call.to),
+                Ok(None) => {}
+                Err(err) => eprintln!("Trade to {} failed: {err}", call.to),
+            }
+        }
+    }
+
+    // 5. Optionally notify configured webhook targets of every event.
+    let webhook_targets = config.webhook_targets();
+    let webhook_sink = (!webhook_targets.is_empty())
+        .then(|| WebhookSink::new(reqwest::Client::new(), webhook_targets));
+
+    // 6. Optionally rebroadcast events to gRPC consumers over `broadcast_tx`.
+    let (broadcast_tx, _) = broadcast::channel::<ObserverEvent>(GRPC_BROADCAST_CAPACITY);
+    if let Ok(grpc_addr) = env::var("GRPC_LISTEN_ADDR") {
+        let addr = grpc_addr.parse()?;
+        let grpc_service = WatchEventsServiceServer::new(GrpcEventServer::new(broadcast_tx.clone()));
+        println!("gRPC event feed listening on {addr}");
+        tokio::spawn(async move {
+            if let Err(err) = Server::builder().add_service(grpc_service).serve(addr).await {
+                eprintln!("gRPC server exited: {err}");
+            }
+        });
+    }
+
+    tokio::select! {
+        _ = async {
+            while let Some(event) = event_rx.recv().await {
+                match &event {
+                    ObserverEvent::NewHead(header) => {
+                        println!("New head: block {}", header.number);
+                    }
+                    ObserverEvent::PendingTransaction(hash) => {
+                        println!("Pending tx: {hash}");
+                    }
+                    ObserverEvent::Contract(contract_event) => {
+                        println!(
+                            "Contract event in block {} (tx {}, log {}): {:?}",
+                            contract_event.block_number,
+                            contract_event.tx_hash,
+                            contract_event.log_index,
+                            contract_event.kind
+                        );
+                    }
+                }
+
+                // Fan out to gRPC clients regardless of whether any are
+                // connected; `send` is a cheap no-op with zero receivers.
+                let _ = broadcast_tx.send(event.clone());
+
+                if let Some(sink) = &webhook_sink {
+                    sink.notify(event);
+                }
+            }
+        } => {}
+        _ = tokio::signal::ctrl_c() => {
+            println!("Shutdown signal received.");
+            let _ = shutdown_tx.send(true);
+        }
+    }
+
+    engine_handle.await?;
+    Ok(())
 }
@@ -0,0 +1,392 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use alloy::eips::BlockNumberOrTag;
+use alloy::primitives::{Address, Bytes, TxHash};
+use alloy::providers::{DynProvider, Provider, ProviderBuilder};
+use alloy::rpc::client::RpcClient;
+use alloy::rpc::json_rpc::RpcError;
+use alloy::rpc::types::{Block, TransactionRequest};
+use alloy::transports::http::{Client, Http};
+use alloy::transports::{TransportError, TransportErrorKind};
+use rand::Rng;
+use url::Url;
+
+/// Endpoints are skipped once they rack up this many consecutive failures.
+const DEFAULT_MAX_CONSECUTIVE_FAILURES: usize = 3;
+/// How long an unhealthy endpoint sits out before being retried.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+/// Backoff applied between retries of the same call against successive endpoints.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+type HttpProvider = DynProvider;
+
+struct Endpoint {
+    url: Url,
+    provider: HttpProvider,
+    consecutive_failures: AtomicUsize,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    fn is_healthy(&self) -> bool {
+        match *self.unhealthy_until.lock().unwrap() {
+            Some(until) => Instant::now() >= until,
+            None => true,
+        }
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        *self.unhealthy_until.lock().unwrap() = None;
+    }
+
+    fn record_failure(&self, max_consecutive_failures: usize, cooldown: Duration) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        if failures >= max_consecutive_failures {
+            *self.unhealthy_until.lock().unwrap() = Some(Instant::now() + cooldown);
+        }
+    }
+}
+
+/// A pool of HTTP provider endpoints, read from a comma-separated list of RPC
+/// URLs, that transparently retries and fails over between them.
+///
+/// All endpoints share a single `reqwest::Client` so connection pooling works
+/// across endpoints instead of churning a fresh client per request.
+pub struct ProviderPool {
+    endpoints: Vec<Endpoint>,
+    next: AtomicUsize,
+    max_consecutive_failures: usize,
+    cooldown: Duration,
+}
+
+impl ProviderPool {
+    /// Builds a pool from a comma-separated list of RPC URLs, e.g.
+    /// `"https://rpc-a,https://rpc-b"`.
+    pub fn new(urls: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let client = Client::new();
+        let mut endpoints = Vec::new();
+
+        for raw in urls.split(',') {
+            let raw = raw.trim();
+            if raw.is_empty() {
+                continue;
+            }
+            let url: Url = raw.parse()?;
+            let http = Http::with_client(client.clone(), url.clone());
+            let provider = ProviderBuilder::new().on_client(RpcClient::new(http, false)).erased();
+            endpoints.push(Endpoint {
+                url,
+                provider,
+                consecutive_failures: AtomicUsize::new(0),
+                unhealthy_until: Mutex::new(None),
+            });
+        }
+
+        if endpoints.is_empty() {
+            return Err("provider pool requires at least one RPC URL".into());
+        }
+
+        Ok(Self {
+            endpoints,
+            next: AtomicUsize::new(0),
+            max_consecutive_failures: DEFAULT_MAX_CONSECUTIVE_FAILURES,
+            cooldown: DEFAULT_COOLDOWN,
+        })
+    }
+
+    pub async fn get_chain_id(&self) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(|provider| Box::pin(async move { provider.get_chain_id().await.map_err(|e| e.into()) }))
+            .await
+    }
+
+    /// Estimates gas for `request`, retrying against another endpoint on a
+    /// transient failure. Used by [`crate::trader::Trader`] before signing.
+    pub async fn estimate_gas(&self, request: TransactionRequest) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(move |provider| {
+            let request = request.clone();
+            Box::pin(async move { provider.estimate_gas(&request).await.map_err(|e| e.into()) })
+        })
+        .await
+    }
+
+    /// Simulates `request` via `eth_call`, retrying against another endpoint
+    /// on a transient failure.
+    pub async fn call(&self, request: TransactionRequest) -> Result<Bytes, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(move |provider| {
+            let request = request.clone();
+            Box::pin(async move { provider.call(&request).await.map_err(|e| e.into()) })
+        })
+        .await
+    }
+
+    /// Fetches the account's current transaction count (nonce), retrying
+    /// against another endpoint on a transient failure.
+    pub async fn get_transaction_count(&self, address: Address) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(move |provider| {
+            Box::pin(async move { provider.get_transaction_count(address).await.map_err(|e| e.into()) })
+        })
+        .await
+    }
+
+    /// Fetches the latest block (used to read the current base fee for gas
+    /// pricing), retrying against another endpoint on a transient failure.
+    pub async fn get_latest_block(&self) -> Result<Option<Block>, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(|provider| {
+            Box::pin(async move {
+                provider
+                    .get_block_by_number(BlockNumberOrTag::Latest, false.into())
+                    .await
+                    .map_err(|e| e.into())
+            })
+        })
+        .await
+    }
+
+    /// Broadcasts a raw signed transaction, retrying against another
+    /// endpoint on a transient failure. Re-submitting the same signed bytes
+    /// is safe: a node that already saw it either rejects the duplicate or
+    /// re-accepts it idempotently.
+    pub async fn send_raw_transaction(&self, raw: Vec<u8>) -> Result<TxHash, Box<dyn std::error::Error + Send + Sync>> {
+        self.call_with_retry(move |provider| {
+            let raw = raw.clone();
+            Box::pin(async move {
+                let pending = provider.send_raw_transaction(&raw).await?;
+                Ok(*pending.tx_hash())
+            })
+        })
+        .await
+    }
+
+    /// Rotates through healthy endpoints, retrying `f` on the next one when
+    /// the current endpoint returns a transient error. Sleeps between
+    /// attempts with exponential backoff plus up to 100ms of jitter; the
+    /// backoff belongs to the call and grows across successive endpoints,
+    /// and no backoff state is kept per endpoint.
+    async fn call_with_retry<F, T>(&self, f: F) -> Result<T, Box<dyn std::error::Error + Send + Sync>>
+    where
+        F: for<'a> Fn(
+            &'a HttpProvider,
+        ) -> Pin<Box<dyn Future<Output = Result<T, Box<dyn std::error::Error + Send + Sync>>> + Send + 'a>>,
+    {
+        let mut last_err: Option<Box<dyn std::error::Error + Send + Sync>> = None;
+        let mut backoff = INITIAL_BACKOFF;
+
+        for _ in 0..self.endpoints.len().max(1) {
+            let Some((idx, endpoint)) = self.next_healthy_endpoint() else {
+                break;
+            };
+
+            match f(&endpoint.provider).await {
+                Ok(value) => {
+                    endpoint.record_success();
+                    return Ok(value);
+                }
+                Err(err) => {
+                    if !is_transient(err.as_ref()) {
+                        // A deterministic error (bad params, unsupported
+                        // method, ...) will fail identically on every other
+                        // endpoint too; don't burn through the pool or
+                        // penalize a perfectly healthy endpoint for it.
+                        return Err(err);
+                    }
+                    eprintln!("Transient RPC failure against {}: {err}", endpoint.url);
+                    endpoint.record_failure(self.max_consecutive_failures, self.cooldown);
+                    last_err = Some(err);
+                    let _ = idx;
+                }
+            }
+
+            let jitter = rand::thread_rng().gen_range(0..100);
+            tokio::time::sleep(backoff + Duration::from_millis(jitter)).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+
+        Err(last_err.unwrap_or_else(|| "no healthy RPC endpoints available".into()))
+    }
+
+    /// Picks the next endpoint in rotation, skipping unhealthy ones.
+    fn next_healthy_endpoint(&self) -> Option<(usize, &Endpoint)> {
+        let len = self.endpoints.len();
+        let start = self.next.fetch_add(1, Ordering::SeqCst);
+        for offset in 0..len {
+            let idx = (start + offset) % len;
+            let endpoint = &self.endpoints[idx];
+            if endpoint.is_healthy() {
+                return Some((idx, endpoint));
+            }
+        }
+        None
+    }
+}
+
+/// Whether `err` looks like a transient failure (timeout, rate limit, 5xx,
+/// or an overloaded-backend JSON-RPC error) worth retrying on another
+/// endpoint, as opposed to a deterministic error (bad params, unsupported
+/// method, reverted call) that would fail identically everywhere.
+///
+/// Classifies on structured transport/JSON-RPC error info (HTTP status, error
+/// code) rather than substring-matching the `Display` text: a deterministic
+/// error whose message happens to contain a digit sequence like a status
+/// code (e.g. a revert reason mentioning a gas amount of `500000`) must not
+/// be misclassified as transient.
+fn is_transient(err: &(dyn std::error::Error + 'static)) -> bool {
+    match err.downcast_ref::<TransportError>() {
+        Some(rpc_err) => is_transient_rpc_error(rpc_err),
+        // Not an RPC transport error (e.g. a local signing failure); nothing
+        // here can be resolved by retrying against another endpoint.
+        None => false,
+    }
+}
+
+fn is_transient_rpc_error(err: &TransportError) -> bool {
+    match err {
+        RpcError::Transport(kind) => is_transient_transport_kind(kind),
+        // The JSON-RPC error code/message is structured application-level
+        // info from the node itself (e.g. infura/alchemy rate-limit codes);
+        // `ErrorPayload::is_retry_err` matches on that, not on coincidental
+        // substrings, so a revert reason mentioning an amount like `500000`
+        // is correctly left deterministic.
+        RpcError::ErrorResp(payload) => payload.is_retry_err(),
+        RpcError::NullResp => true,
+        RpcError::SerError(_)
+        | RpcError::DeserError { .. }
+        | RpcError::UnsupportedFeature(_)
+        | RpcError::LocalUsageError(_) => false,
+    }
+}
+
+fn is_transient_transport_kind(kind: &TransportErrorKind) -> bool {
+    match kind {
+        // Broader than alloy's own `HttpError::is_temporarily_unavailable`
+        // (503 only): any 5xx is the backend's fault, not ours, and worth
+        // retrying against another endpoint, matching this pool's original
+        // 500/502/503/504 behavior.
+        TransportErrorKind::HttpError(http_err) => {
+            http_err.is_rate_limit_err() || (500..=599).contains(&http_err.status)
+        }
+        // The backend connection task died; reconnecting (possibly to a
+        // different endpoint) can resolve it.
+        TransportErrorKind::BackendGone => true,
+        // This provider was built without subscription support; every other
+        // HTTP endpoint in the pool has the same limitation, so retrying
+        // elsewhere can't help.
+        TransportErrorKind::PubsubUnavailable => false,
+        TransportErrorKind::MissingBatchResponse(_) => false,
+        // Opaque wrapped errors (e.g. the underlying `reqwest` transport
+        // failing to even get an HTTP response) have no structured fields to
+        // inspect, only a message. Unlike JSON-RPC application errors, these
+        // are connection-level failures (timeouts, resets, refusals) whose
+        // text isn't going to coincidentally contain a status-like digit
+        // sequence, so substring matching here is safe.
+        TransportErrorKind::Custom(inner) => {
+            let message = inner.to_string().to_ascii_lowercase();
+            const TRANSIENT_MARKERS: &[&str] =
+                &["timeout", "timed out", "connection reset", "connection refused", "dns"];
+            TRANSIENT_MARKERS.iter().any(|marker| message.contains(marker))
+        }
+        // `TransportErrorKind` is `#[non_exhaustive]`; treat anything added
+        // upstream as non-transient until it's explicitly classified.
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_endpoint() -> Endpoint {
+        let client = Client::new();
+        let url: Url = "http://127.0.0.1:9".parse().unwrap();
+        let http = Http::with_client(client, url.clone());
+        let provider = ProviderBuilder::new().on_client(RpcClient::new(http, false)).erased();
+        Endpoint {
+            url,
+            provider,
+            consecutive_failures: AtomicUsize::new(0),
+            unhealthy_until: Mutex::new(None),
+        }
+    }
+
+    #[test]
+    fn endpoint_becomes_unhealthy_after_max_consecutive_failures() {
+        let endpoint = dummy_endpoint();
+        assert!(endpoint.is_healthy());
+
+        endpoint.record_failure(3, Duration::from_secs(30));
+        assert!(endpoint.is_healthy());
+        endpoint.record_failure(3, Duration::from_secs(30));
+        assert!(endpoint.is_healthy());
+        endpoint.record_failure(3, Duration::from_secs(30));
+        assert!(!endpoint.is_healthy());
+    }
+
+    #[test]
+    fn endpoint_recovers_on_success() {
+        let endpoint = dummy_endpoint();
+        endpoint.record_failure(1, Duration::from_secs(30));
+        assert!(!endpoint.is_healthy());
+
+        endpoint.record_success();
+        assert!(endpoint.is_healthy());
+    }
+
+    fn boxed(err: TransportError) -> Box<dyn std::error::Error + Send + Sync> {
+        Box::new(err)
+    }
+
+    fn http_error(status: u16) -> TransportError {
+        RpcError::Transport(TransportErrorKind::HttpError(alloy::transports::HttpError {
+            status,
+            body: String::new(),
+        }))
+    }
+
+    fn error_resp(code: i64, message: &str) -> TransportError {
+        RpcError::ErrorResp(alloy::rpc::json_rpc::ErrorPayload {
+            code,
+            message: message.to_string().into(),
+            data: None,
+        })
+    }
+
+    fn custom_error(message: &str) -> TransportError {
+        TransportErrorKind::custom_str(message)
+    }
+
+    #[test]
+    fn classifies_transient_errors() {
+        assert!(is_transient(boxed(http_error(429)).as_ref()));
+        assert!(is_transient(boxed(http_error(500)).as_ref()));
+        assert!(is_transient(boxed(http_error(502)).as_ref()));
+        assert!(is_transient(boxed(http_error(503)).as_ref()));
+        assert!(is_transient(boxed(http_error(504)).as_ref()));
+        assert!(is_transient(boxed(error_resp(-32005, "exceeded project rate limit")).as_ref()));
+        assert!(is_transient(boxed(error_resp(429, "alchemy says slow down")).as_ref()));
+        assert!(is_transient(boxed(custom_error("operation timed out")).as_ref()));
+        assert!(is_transient(boxed(custom_error("connection reset by peer")).as_ref()));
+    }
+
+    #[test]
+    fn classifies_deterministic_errors_as_not_transient() {
+        assert!(!is_transient(boxed(error_resp(3, "execution reverted: insufficient balance")).as_ref()));
+        assert!(!is_transient(boxed(error_resp(-32602, "invalid params")).as_ref()));
+        assert!(!is_transient(boxed(error_resp(-32601, "method not found")).as_ref()));
+        assert!(!is_transient(boxed(custom_error("signature verification failed")).as_ref()));
+    }
+
+    #[test]
+    fn does_not_misclassify_deterministic_errors_containing_status_like_digits() {
+        // A revert or gas-limit message that happens to contain a substring
+        // like "500" or "503" must not be treated as a transient 5xx error.
+        assert!(!is_transient(
+            boxed(error_resp(3, "execution reverted: gas required exceeds allowance (500000)")).as_ref()
+        ));
+        assert!(!is_transient(boxed(error_resp(3, "execution reverted: order 503 already filled")).as_ref()));
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use alloy::providers::Provider;
+use futures_util::StreamExt;
+use tokio::sync::{mpsc, watch};
+use tokio::time::sleep;
+
+use crate::events::ObserverEvent;
+
+/// Initial delay before the first reconnect attempt after a stream drops.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound on the reconnect backoff so we don't wait forever between tries.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Subscribes to `newPendingTransactions` and `newHeads` on a WS provider and
+/// fans the decoded items into `tx` as [`ObserverEvent`]s, reconnecting with
+/// exponential backoff whenever a subscription stream ends.
+pub struct SubscriptionEngine<P> {
+    provider: P,
+    tx: mpsc::Sender<ObserverEvent>,
+}
+
+impl<P> SubscriptionEngine<P>
+where
+    P: Provider + Clone + 'static,
+{
+    pub fn new(provider: P, tx: mpsc::Sender<ObserverEvent>) -> Self {
+        Self { provider, tx }
+    }
+
+    /// Runs the subscription loop until `shutdown` is signalled or the
+    /// receiving end of the event channel is dropped.
+    pub async fn run(self, mut shutdown: watch::Receiver<bool>) {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            let shutdown_for_subscribe = shutdown.clone();
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        println!("Subscription engine shutting down.");
+                        return;
+                    }
+                }
+                result = self.subscribe_once(shutdown_for_subscribe, &mut backoff) => {
+                    match result {
+                        Ok(()) => {
+                            // Shutdown requested mid-subscription, or the
+                            // event receiver was dropped.
+                            return;
+                        }
+                        Err(err) => {
+                            eprintln!(
+                                "Subscription stream ended ({err}); reconnecting in {:?}",
+                                backoff
+                            );
+                            sleep(backoff).await;
+                            backoff = (backoff * 2).min(MAX_BACKOFF);
+                            continue;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Opens both subscriptions and forwards events until either stream ends
+    /// or a shutdown is signalled. Resets `backoff` to [`INITIAL_BACKOFF`] as
+    /// soon as both subscriptions are live, so a connection that blips after
+    /// running healthy for a while doesn't inherit a backoff grown from
+    /// earlier, unrelated failures.
+    async fn subscribe_once(
+        &self,
+        mut shutdown: watch::Receiver<bool>,
+        backoff: &mut Duration,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let pending_sub = self.provider.subscribe_pending_transactions().await?;
+        let heads_sub = self.provider.subscribe_blocks().await?;
+
+        *backoff = INITIAL_BACKOFF;
+
+        let mut pending_stream = pending_sub.into_stream();
+        let mut heads_stream = heads_sub.into_stream();
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+                maybe_hash = pending_stream.next() => {
+                    match maybe_hash {
+                        Some(hash) => {
+                            if self.tx.send(ObserverEvent::PendingTransaction(hash)).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                        None => return Err("pending transaction stream closed".into()),
+                    }
+                }
+                maybe_header = heads_stream.next() => {
+                    match maybe_header {
+                        Some(header) => {
+                            if self.tx.send(ObserverEvent::NewHead(Box::new(header))).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                        None => return Err("new heads stream closed".into()),
+                    }
+                }
+            }
+        }
+    }
+}
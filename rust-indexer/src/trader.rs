@@ -0,0 +1,164 @@
+use tokio::sync::Mutex;
+
+use alloy::consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+use alloy::eips::eip2718::Encodable2718;
+use alloy::network::TxSignerSync;
+use alloy::primitives::{Address, Bytes, TxHash, TxKind, U256};
+use alloy::rpc::types::TransactionRequest;
+use alloy::signers::local::PrivateKeySigner;
+
+use crate::provider_pool::ProviderPool;
+
+/// Default tip added on top of the latest base fee when `PRIORITY_FEE_WEI`
+/// is not configured, in wei.
+const DEFAULT_PRIORITY_FEE_WEI: u128 = 1_500_000_000; // 1.5 gwei
+
+/// A call against a target contract the trader should execute.
+#[derive(Debug, Clone)]
+pub struct ContractCall {
+    pub to: Address,
+    pub data: Bytes,
+    pub value: U256,
+}
+
+/// Signs and submits EIP-1559 transactions for [`ContractCall`]s. Built from
+/// a private key loaded from the environment (never logged — only the
+/// derived address is) and a [`ProviderPool`], so the estimate/call/nonce/
+/// broadcast round trips around a send get the same retry-and-failover
+/// behavior as every other read path instead of pinning the trader to a
+/// single RPC endpoint.
+///
+/// In `dry_run` mode, [`Trader::execute`] performs `eth_call` and
+/// `estimate_gas` against the call and logs the outcome without ever
+/// signing or broadcasting anything, so strategies can be validated against
+/// mainnet state safely.
+pub struct Trader {
+    provider: ProviderPool,
+    signer: PrivateKeySigner,
+    address: Address,
+    chain_id: u64,
+    priority_fee_wei: u128,
+    dry_run: bool,
+    /// The nonce to use for the next sent transaction. Held locked from the
+    /// moment a nonce is drawn until the send either succeeds (advance past
+    /// it) or fails (resync from the chain), so a failed send never leaves
+    /// the counter pointing past a nonce the chain never saw.
+    next_nonce: Mutex<u64>,
+}
+
+impl Trader {
+    /// Builds a trader from a hex-encoded private key, fetching the starting
+    /// nonce from the pool so subsequent sends can be sequenced locally
+    /// without round-tripping `eth_getTransactionCount` each time.
+    pub async fn new(
+        provider: ProviderPool,
+        private_key_hex: &str,
+        chain_id: u64,
+        dry_run: bool,
+        priority_fee_wei: Option<u128>,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let signer: PrivateKeySigner = private_key_hex.parse()?;
+        let address = signer.address();
+        let next_nonce = provider.get_transaction_count(address).await?;
+        let priority_fee_wei = priority_fee_wei.unwrap_or(DEFAULT_PRIORITY_FEE_WEI);
+
+        println!("Trader initialized for address {address} (dry_run={dry_run}, priority_fee_wei={priority_fee_wei})");
+
+        Ok(Self {
+            provider,
+            signer,
+            address,
+            chain_id,
+            priority_fee_wei,
+            dry_run,
+            next_nonce: Mutex::new(next_nonce),
+        })
+    }
+
+    /// Executes `call`. In live mode this signs and submits an EIP-1559
+    /// transaction, returning the broadcast tx hash. In dry-run mode it
+    /// simulates via `eth_call`/`estimate_gas`, logs the result, and returns
+    /// `None` without ever broadcasting.
+    pub async fn execute(&self, call: ContractCall) -> Result<Option<TxHash>, Box<dyn std::error::Error + Send + Sync>> {
+        let request = TransactionRequest::default()
+            .from(self.address)
+            .to(call.to)
+            .value(call.value)
+            .input(call.data.clone().into());
+
+        let gas_limit = self.provider.estimate_gas(request.clone()).await?;
+
+        if self.dry_run {
+            let output = self.provider.call(request).await?;
+            println!(
+                "[dry-run] call to {} would use ~{gas_limit} gas, returned {} bytes",
+                call.to,
+                output.len()
+            );
+            return Ok(None);
+        }
+
+        let head = self
+            .provider
+            .get_latest_block()
+            .await?
+            .ok_or("no latest block available to price gas from")?;
+        let base_fee = head
+            .header
+            .base_fee_per_gas
+            .ok_or("chain does not report EIP-1559 base fee")? as u128;
+
+        let max_priority_fee_per_gas = self.priority_fee_wei;
+        let max_fee_per_gas = base_fee.saturating_mul(2).saturating_add(max_priority_fee_per_gas);
+
+        // Held across signing and the send below: advanced only once the
+        // send actually succeeds, and resynced from the chain if it
+        // doesn't, so a failed send can never strand the local counter
+        // past a nonce the chain never received.
+        let mut next_nonce = self.next_nonce.lock().await;
+        let nonce = *next_nonce;
+
+        let mut tx = TxEip1559 {
+            chain_id: self.chain_id,
+            nonce,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to: TxKind::Call(call.to),
+            value: call.value,
+            access_list: Default::default(),
+            input: call.data,
+        };
+
+        let signature = self.signer.sign_transaction_sync(&mut tx)?;
+        let signed = tx.into_signed(signature);
+        let envelope = TxEnvelope::Eip1559(signed);
+
+        let mut raw = Vec::new();
+        envelope.encode_2718(&mut raw);
+
+        match self.provider.send_raw_transaction(raw).await {
+            Ok(tx_hash) => {
+                *next_nonce = nonce + 1;
+                println!("Submitted trade to {}: {tx_hash}", call.to);
+                Ok(Some(tx_hash))
+            }
+            Err(err) => {
+                // The nonce we drew may or may not have been consumed
+                // (timeout, dropped connection, replacement underpriced,
+                // ...); re-derive the true next nonce from the chain
+                // rather than guessing.
+                match self.provider.get_transaction_count(self.address).await {
+                    Ok(fresh) => *next_nonce = fresh,
+                    Err(resync_err) => {
+                        eprintln!(
+                            "Failed to resync nonce for {} after send failure: {resync_err}",
+                            self.address
+                        );
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+}
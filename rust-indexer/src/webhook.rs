@@ -0,0 +1,181 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use serde::Serialize;
+use sha2::Sha256;
+use tokio::sync::{mpsc, Semaphore};
+
+use crate::events::ObserverEvent;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Capacity of the bounded queue events sit in before being dispatched.
+const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+/// How many times a failed POST is retried before being dropped.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+/// How many in-flight deliveries are allowed against a single target at once.
+const DEFAULT_CONCURRENCY_PER_TARGET: usize = 4;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A single webhook target: the URL to POST to plus the HMAC secret (if any)
+/// used to sign the body so the receiver can verify authenticity.
+#[derive(Clone)]
+pub struct WebhookTarget {
+    pub url: String,
+    pub secret: Option<String>,
+}
+
+/// A JSON-friendly projection of [`ObserverEvent`], used both for webhook
+/// payloads and the gRPC feed's `payload_json` field. Kept separate from the
+/// event types themselves since the decoded `sol!` event structs don't
+/// implement `Serialize`.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum WebhookPayload {
+    NewHead { block_number: u64, hash: String },
+    PendingTransaction { tx_hash: String },
+    ContractEvent {
+        block_number: u64,
+        tx_hash: String,
+        log_index: u64,
+        event: String,
+    },
+}
+
+impl From<&ObserverEvent> for WebhookPayload {
+    fn from(event: &ObserverEvent) -> Self {
+        match event {
+            ObserverEvent::NewHead(header) => WebhookPayload::NewHead {
+                block_number: header.number,
+                hash: header.hash.to_string(),
+            },
+            ObserverEvent::PendingTransaction(hash) => {
+                WebhookPayload::PendingTransaction { tx_hash: hash.to_string() }
+            }
+            ObserverEvent::Contract(event) => WebhookPayload::ContractEvent {
+                block_number: event.block_number,
+                tx_hash: event.tx_hash.to_string(),
+                log_index: event.log_index,
+                event: format!("{:?}", event.kind),
+            },
+        }
+    }
+}
+
+/// Outbound webhook notifier. Events handed to [`WebhookSink::notify`] are
+/// queued and POSTed to every configured target, signed with HMAC-SHA256
+/// over the body when the target has a secret, retried with backoff on
+/// failure, and limited to a fixed number of in-flight deliveries per target
+/// so one slow endpoint can't stall delivery to the others.
+pub struct WebhookSink {
+    tx: mpsc::Sender<ObserverEvent>,
+}
+
+struct Inner {
+    client: Client,
+    targets: Vec<WebhookTarget>,
+    semaphores: Vec<Arc<Semaphore>>,
+    max_retries: u32,
+}
+
+impl WebhookSink {
+    /// Builds a sink for the given targets and spawns its dispatch task.
+    pub fn new(client: Client, targets: Vec<WebhookTarget>) -> Self {
+        let semaphores = targets
+            .iter()
+            .map(|_| Arc::new(Semaphore::new(DEFAULT_CONCURRENCY_PER_TARGET)))
+            .collect();
+
+        let inner = Arc::new(Inner {
+            client,
+            targets,
+            semaphores,
+            max_retries: DEFAULT_MAX_RETRIES,
+        });
+
+        let (tx, rx) = mpsc::channel(DEFAULT_QUEUE_CAPACITY);
+        tokio::spawn(Self::dispatch_loop(inner, rx));
+
+        Self { tx }
+    }
+
+    /// Queues `event` for delivery. Drops the event (logging a warning) if
+    /// the bounded queue is full rather than blocking the producer.
+    pub fn notify(&self, event: ObserverEvent) {
+        if self.tx.try_send(event).is_err() {
+            eprintln!("Webhook queue full; dropping event");
+        }
+    }
+
+    async fn dispatch_loop(inner: Arc<Inner>, mut rx: mpsc::Receiver<ObserverEvent>) {
+        while let Some(event) = rx.recv().await {
+            let payload = WebhookPayload::from(&event);
+            let body = match serde_json::to_vec(&payload) {
+                Ok(body) => body,
+                Err(err) => {
+                    eprintln!("Failed to serialize webhook payload: {err}");
+                    continue;
+                }
+            };
+
+            for (target, semaphore) in inner.targets.iter().zip(inner.semaphores.iter()) {
+                let inner = inner.clone();
+                let target = target.clone();
+                let semaphore = semaphore.clone();
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire_owned().await;
+                    deliver(&inner, &target, body).await;
+                });
+            }
+        }
+    }
+}
+
+async fn deliver(inner: &Inner, target: &WebhookTarget, body: Vec<u8>) {
+    let mut backoff = INITIAL_BACKOFF;
+
+    for attempt in 0..=inner.max_retries {
+        let mut request = inner
+            .client
+            .post(&target.url)
+            .header("Content-Type", "application/json");
+
+        if let Some(secret) = &target.secret {
+            request = request.header("X-Signature-256", sign(secret, &body));
+        }
+
+        match request.body(body.clone()).send().await {
+            Ok(response) if response.status().is_success() => return,
+            Ok(response) => {
+                eprintln!("Webhook POST to {} returned {}", target.url, response.status());
+            }
+            Err(err) => {
+                eprintln!("Webhook POST to {} failed: {err}", target.url);
+            }
+        }
+
+        if attempt == inner.max_retries {
+            break;
+        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+
+    eprintln!(
+        "Giving up delivering webhook to {} after {} attempts",
+        target.url,
+        inner.max_retries + 1
+    );
+}
+
+/// Computes the `X-Signature-256` header value: hex-encoded HMAC-SHA256 over
+/// the request body using the target's configured secret.
+fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}